@@ -4,7 +4,24 @@
 //! It supports both equal division (3x3 grid) and custom-sized sub-images.
 
 use image::{GenericImageView, ImageBuffer, Rgba};
-use std::path::Path;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// File extensions (lowercase, without the leading dot) that `split_directory`
+/// will attempt to open and split.
+const SUPPORTED_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "gif", "bmp", "ico", "tiff", "webp"];
+
+/// Returns `true` if `path` has one of the extensions in [`SUPPORTED_EXTENSIONS`].
+fn has_supported_extension(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| SUPPORTED_EXTENSIONS.contains(&ext.to_lowercase().as_str()))
+        .unwrap_or(false)
+}
 
 /// Splits an image into 9 equal parts (3x3 grid).
 /// 
@@ -117,6 +134,618 @@ pub fn split_image_with_size<P: AsRef<Path>>(
     Ok(sub_images)
 }
 
+/// Splits every supported image file in a directory (recursively) into
+/// sub-images of the given size.
+///
+/// This walks `dir` using `walkdir`, skipping anything whose extension isn't
+/// in the list of supported image formats, and calls [`split_image_with_size`]
+/// on each match. A failure splitting one file (corrupt image, unsupported
+/// format variant, etc.) doesn't abort the whole walk - it's recorded as an
+/// `Err` for that path so the rest of the directory still gets processed.
+///
+/// # Arguments
+///
+/// * `dir` - Path to the directory to walk
+/// * `sub_width` - Desired width of each sub-image
+/// * `sub_height` - Desired height of each sub-image
+///
+/// # Returns
+///
+/// * `HashMap<PathBuf, Result<Vec<ImageBuffer<Rgba<u8>, Vec<u8>>>, image::ImageError>>` -
+///   one entry per supported image file found, mapping its path to either its
+///   split sub-images or the error that occurred while splitting it
+///
+/// # Example
+///
+/// ```no_run
+/// use image_splitting::split_directory;
+///
+/// let results = split_directory("path/to/sprites", 100, 100);
+/// for (path, result) in results {
+///     match result {
+///         Ok(sub_images) => println!("{:?}: {} tiles", path, sub_images.len()),
+///         Err(err) => eprintln!("{:?}: failed ({err})", path),
+///     }
+/// }
+/// ```
+pub fn split_directory<P: AsRef<Path>>(
+    dir: P,
+    sub_width: u32,
+    sub_height: u32,
+) -> HashMap<PathBuf, Result<Vec<ImageBuffer<Rgba<u8>, Vec<u8>>>, image::ImageError>> {
+    let mut results = HashMap::new();
+
+    for entry in WalkDir::new(dir)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file())
+    {
+        let path = entry.into_path();
+        if !has_supported_extension(&path) {
+            continue;
+        }
+
+        let result = split_image_with_size(&path, sub_width, sub_height);
+        results.insert(path, result);
+    }
+
+    results
+}
+
+/// Computes cumulative pixel boundaries for a set of weights along a dimension.
+///
+/// Given weights `[w0, w1, ..., wn]` and a total `dimension` size, returns
+/// `weights.len() + 1` boundaries `[0, b0, b1, ..., bn]` (with `bn == dimension`) where
+/// `b_i = floor(sum(weights[..=i]) / sum(weights) * dimension)`. Using the
+/// running prefix sum (rather than rounding each band's width independently)
+/// guarantees the boundaries are monotonic and the last one always lands
+/// exactly on `dimension`, so rounding never drops or overlaps pixels.
+///
+/// Returns `None` if `weights` sums to zero, since there would be no way to
+/// distribute `dimension` across them without dividing by zero.
+fn weighted_boundaries(weights: &[u32], dimension: u32) -> Option<Vec<u32>> {
+    let total: u64 = weights.iter().map(|&w| w as u64).sum();
+    if total == 0 {
+        return None;
+    }
+    let dimension = dimension as u64;
+
+    let mut boundaries = Vec::with_capacity(weights.len() + 1);
+    boundaries.push(0);
+
+    let mut prefix: u64 = 0;
+    for &weight in weights {
+        prefix += weight as u64;
+        boundaries.push((prefix * dimension / total) as u32);
+    }
+
+    Some(boundaries)
+}
+
+/// Error returned by [`split_image_weighted`].
+#[derive(Debug)]
+pub enum SplitWeightedError {
+    /// The source image couldn't be opened.
+    Image(image::ImageError),
+    /// `row_weights` or `col_weights` summed to zero, so there's no way to
+    /// distribute the image's dimensions across them.
+    ZeroWeight,
+}
+
+impl fmt::Display for SplitWeightedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SplitWeightedError::Image(err) => write!(f, "image error: {err}"),
+            SplitWeightedError::ZeroWeight => {
+                write!(f, "row_weights and col_weights must each sum to more than zero")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SplitWeightedError {}
+
+impl From<image::ImageError> for SplitWeightedError {
+    fn from(err: image::ImageError) -> Self {
+        SplitWeightedError::Image(err)
+    }
+}
+
+/// Splits an image into rows and columns whose sizes are proportional to the
+/// given weights, rather than equal or fixed pixel sizes.
+///
+/// For example `row_weights = [2, 3, 1, 5]` divides the height into
+/// `2 + 3 + 1 + 5 = 11` equal bands and makes the first row 2 bands tall, the
+/// second 3 bands, and so on; `col_weights` works the same way across the
+/// width. This generalizes [`split_image`]'s hard-coded 3x3 grid into
+/// arbitrary uneven layouts.
+///
+/// # Arguments
+///
+/// * `image_path` - Path to the input image file
+/// * `row_weights` - Relative height of each row; must be non-empty
+/// * `col_weights` - Relative width of each column; must be non-empty
+///
+/// # Returns
+///
+/// * `Result<Vec<ImageBuffer<Rgba<u8>, Vec<u8>>>, SplitWeightedError>` - the
+///   sub-images in row-major order (`row_weights.len() * col_weights.len()`
+///   of them), or an error if the image can't be opened or the weights are
+///   all zero
+///
+/// # Example
+///
+/// ```no_run
+/// use image_splitting::split_image_weighted;
+///
+/// let sub_images = split_image_weighted("path/to/image.png", &[2, 3, 1, 5], &[1, 1])?;
+/// assert_eq!(sub_images.len(), 8);
+/// # Ok::<(), image_splitting::SplitWeightedError>(())
+/// ```
+pub fn split_image_weighted<P: AsRef<Path>>(
+    image_path: P,
+    row_weights: &[u32],
+    col_weights: &[u32],
+) -> Result<Vec<ImageBuffer<Rgba<u8>, Vec<u8>>>, SplitWeightedError> {
+    // Load the image
+    let img = image::open(image_path)?;
+
+    // Get image dimensions
+    let (width, height) = img.dimensions();
+
+    let row_boundaries = weighted_boundaries(row_weights, height).ok_or(SplitWeightedError::ZeroWeight)?;
+    let col_boundaries = weighted_boundaries(col_weights, width).ok_or(SplitWeightedError::ZeroWeight)?;
+
+    let mut sub_images = Vec::new();
+
+    // Split the image in row-major order using the weighted boundaries
+    for row in 0..row_weights.len() {
+        let y_pos = row_boundaries[row];
+        let row_height = row_boundaries[row + 1] - y_pos;
+
+        for col in 0..col_weights.len() {
+            let x_pos = col_boundaries[col];
+            let col_width = col_boundaries[col + 1] - x_pos;
+
+            let sub_img = img.crop_imm(x_pos, y_pos, col_width, row_height);
+
+            sub_images.push(sub_img.to_rgba8());
+        }
+    }
+
+    Ok(sub_images)
+}
+
+/// Error returned by [`save_splits`] and [`split_and_save`].
+///
+/// Wraps the two ways writing split tiles to disk can fail: the image
+/// couldn't be decoded/split, or a tile couldn't be written.
+#[derive(Debug)]
+pub enum SaveError {
+    Io(io::Error),
+    Image(image::ImageError),
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SaveError::Io(err) => write!(f, "I/O error: {err}"),
+            SaveError::Image(err) => write!(f, "image error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for SaveError {}
+
+impl From<io::Error> for SaveError {
+    fn from(err: io::Error) -> Self {
+        SaveError::Io(err)
+    }
+}
+
+impl From<image::ImageError> for SaveError {
+    fn from(err: image::ImageError) -> Self {
+        SaveError::Image(err)
+    }
+}
+
+/// Writes a vector of split tiles to disk as `{prefix}_{row}_{col}.{ext}`.
+///
+/// `sub_images` is assumed to be in row-major order with `cols` columns per
+/// row (the order [`split_image`], [`split_image_with_size`] and
+/// [`split_image_weighted`] all produce), so the row/col of each tile is
+/// derived from its index. The output directory is created if it doesn't
+/// already exist.
+///
+/// # Arguments
+///
+/// * `sub_images` - The split tiles, in row-major order
+/// * `cols` - Number of columns per row, used to derive each tile's row/col
+/// * `output_dir` - Directory to write the tiles into
+/// * `prefix` - Filename prefix for each tile
+/// * `format` - Image format to encode each tile as
+///
+/// # Returns
+///
+/// * `Result<Vec<PathBuf>, SaveError>` - the paths written, in the same order
+///   as `sub_images`
+///
+/// # Example
+///
+/// ```no_run
+/// use image::ImageFormat;
+/// use image_splitting::{split_image_with_size, save_splits};
+///
+/// let sub_images = split_image_with_size("path/to/image.png", 100, 100)?;
+/// let cols = 4;
+/// let paths = save_splits(&sub_images, cols, "out/tiles", "tile", ImageFormat::Png)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn save_splits<P: AsRef<Path>>(
+    sub_images: &[ImageBuffer<Rgba<u8>, Vec<u8>>],
+    cols: u32,
+    output_dir: P,
+    prefix: &str,
+    format: image::ImageFormat,
+) -> Result<Vec<PathBuf>, SaveError> {
+    let output_dir = output_dir.as_ref();
+    fs::create_dir_all(output_dir)?;
+
+    let ext = format.extensions_str().first().unwrap_or(&"img");
+
+    let mut paths = Vec::with_capacity(sub_images.len());
+
+    for (index, sub_image) in sub_images.iter().enumerate() {
+        let row = index as u32 / cols;
+        let col = index as u32 % cols;
+
+        let path = output_dir.join(format!("{prefix}_{row}_{col}.{ext}"));
+        sub_image.save_with_format(&path, format)?;
+        paths.push(path);
+    }
+
+    Ok(paths)
+}
+
+/// Splits an image into sub-images of the given size and writes each one to
+/// disk, combining [`split_image_with_size`] and [`save_splits`].
+///
+/// # Arguments
+///
+/// * `image_path` - Path to the input image file
+/// * `sub_width` - Desired width of each sub-image
+/// * `sub_height` - Desired height of each sub-image
+/// * `output_dir` - Directory to write the tiles into
+/// * `prefix` - Filename prefix for each tile
+/// * `format` - Image format to encode each tile as
+///
+/// # Returns
+///
+/// * `Result<Vec<PathBuf>, SaveError>` - the paths written, in row-major order
+///
+/// # Example
+///
+/// ```no_run
+/// use image::ImageFormat;
+/// use image_splitting::split_and_save;
+///
+/// let paths = split_and_save("path/to/image.png", 100, 100, "out/tiles", "tile", ImageFormat::Png)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn split_and_save<P: AsRef<Path>, Q: AsRef<Path>>(
+    image_path: P,
+    sub_width: u32,
+    sub_height: u32,
+    output_dir: Q,
+    prefix: &str,
+    format: image::ImageFormat,
+) -> Result<Vec<PathBuf>, SaveError> {
+    let img = image::open(&image_path)?;
+    let (width, _) = img.dimensions();
+    let cols = (width + sub_width - 1) / sub_width;
+
+    let sub_images = split_image_with_size(image_path, sub_width, sub_height)?;
+    save_splits(&sub_images, cols, output_dir, prefix, format)
+}
+
+/// A zero-copy view into a rectangular sub-region of a decoded RGBA image
+/// buffer, produced by [`split_views`].
+///
+/// `stride` is the row stride (in samples) of the *parent* buffer, not the
+/// view itself - each row of the view is `width * 4` samples wide but starts
+/// `stride` samples after the previous one. Call [`SplitView::to_owned`] to
+/// copy the region out into its own `ImageBuffer` when you actually need one.
+#[derive(Debug, Clone, Copy)]
+pub struct SplitView<'a> {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+    pub stride: usize,
+    source: &'a ImageBuffer<Rgba<u8>, Vec<u8>>,
+}
+
+impl<'a> SplitView<'a> {
+    /// Returns the raw RGBA samples of `row` (`0..self.height`) within this
+    /// view, borrowed directly from the parent buffer's backing `Vec` - no
+    /// allocation or copy. This is what lets a caller stream a tile straight
+    /// into an encoder without materializing an `ImageBuffer`.
+    pub fn row(&self, row: u32) -> &'a [u8] {
+        assert!(
+            row < self.height,
+            "row {row} out of bounds for view of height {}",
+            self.height
+        );
+
+        let raw = self.source.as_raw();
+        let start = (self.y as usize + row as usize) * self.stride + self.x as usize * 4;
+        let end = start + self.width as usize * 4;
+        &raw[start..end]
+    }
+
+    /// Returns an iterator over this view's rows as raw RGBA sample slices,
+    /// each borrowed directly from the parent buffer without copying.
+    pub fn rows(&self) -> impl Iterator<Item = &'a [u8]> + 'a {
+        let view = *self;
+        (0..view.height).map(move |row| view.row(row))
+    }
+
+    /// Materializes this view into an owned `ImageBuffer`, copying its pixels
+    /// out of the parent buffer. Prefer [`SplitView::row`]/[`SplitView::rows`]
+    /// when a caller only needs to read or stream the tile, since this
+    /// allocates a fresh buffer.
+    pub fn to_owned(&self) -> ImageBuffer<Rgba<u8>, Vec<u8>> {
+        ImageBuffer::from_fn(self.width, self.height, |x, y| {
+            *self.source.get_pixel(self.x + x, self.y + y)
+        })
+    }
+}
+
+/// Iterator over [`SplitView`]s tiling a decoded image without allocating a
+/// fresh buffer per tile, returned by [`split_views`].
+pub struct SplitViews<'a> {
+    source: &'a ImageBuffer<Rgba<u8>, Vec<u8>>,
+    sub_width: u32,
+    sub_height: u32,
+    num_cols: u32,
+    num_rows: u32,
+    index: u32,
+}
+
+impl<'a> Iterator for SplitViews<'a> {
+    type Item = SplitView<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.num_cols * self.num_rows {
+            return None;
+        }
+
+        let row = self.index / self.num_cols;
+        let col = self.index % self.num_cols;
+        self.index += 1;
+
+        let (total_width, total_height) = self.source.dimensions();
+        let x = col * self.sub_width;
+        let y = row * self.sub_height;
+        let width = self.sub_width.min(total_width - x);
+        let height = self.sub_height.min(total_height - y);
+
+        Some(SplitView {
+            x,
+            y,
+            width,
+            height,
+            stride: total_width as usize * 4,
+            source: self.source,
+        })
+    }
+}
+
+/// Tiles an already-decoded RGBA image into [`SplitView`]s without copying
+/// pixel data, unlike [`split_image_with_size`] which calls `to_rgba8()` (and
+/// allocates a fresh buffer) for every tile.
+///
+/// This is for callers who only need to read or stream tiles - e.g. feeding
+/// them to an encoder one at a time - and want to avoid N full allocations.
+/// Call [`SplitView::to_owned`] on a view to materialize an `ImageBuffer`
+/// when one is actually needed. The last row and column may be smaller if the
+/// image dimensions aren't perfectly divisible by the sub-image size.
+///
+/// # Arguments
+///
+/// * `source` - The already-decoded source image
+/// * `sub_width` - Desired width of each view
+/// * `sub_height` - Desired height of each view
+///
+/// # Returns
+///
+/// * `SplitViews<'_>` - an iterator of views in row-major order, borrowing
+///   from `source`
+///
+/// # Example
+///
+/// ```no_run
+/// use image_splitting::split_views;
+///
+/// let source = image::open("path/to/image.png")?.to_rgba8();
+/// for view in split_views(&source, 100, 100) {
+///     let owned = view.to_owned();
+///     assert_eq!(owned.width(), view.width);
+/// }
+/// # Ok::<(), image::ImageError>(())
+/// ```
+pub fn split_views(
+    source: &ImageBuffer<Rgba<u8>, Vec<u8>>,
+    sub_width: u32,
+    sub_height: u32,
+) -> SplitViews<'_> {
+    let (width, height) = source.dimensions();
+    let num_cols = (width + sub_width - 1) / sub_width;
+    let num_rows = (height + sub_height - 1) / sub_height;
+
+    SplitViews {
+        source,
+        sub_width,
+        sub_height,
+        num_cols,
+        num_rows,
+        index: 0,
+    }
+}
+
+/// Policy for handling a requested grid that extends past the image bounds
+/// in [`split_grid`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutOfBoundsPolicy {
+    /// Return a [`GridError::OutOfBounds`] if the grid doesn't fit inside the image.
+    Error,
+    /// Clip any cells that extend past the image edge down to what's available.
+    Clip,
+}
+
+/// Specifies an inset grid to slice out of a source image: starting at
+/// `(start_x, start_y)`, extract exactly `rows * cols` cells of
+/// `cell_width * cell_height`. Used by [`split_grid`] for sprite-sheet
+/// workflows where the meaningful content is a grid with margins/padding
+/// around it, rather than the whole image.
+#[derive(Debug, Clone, Copy)]
+pub struct GridSpec {
+    pub start_x: u32,
+    pub start_y: u32,
+    pub cell_width: u32,
+    pub cell_height: u32,
+    pub rows: u32,
+    pub cols: u32,
+}
+
+/// Error returned by [`split_grid`].
+#[derive(Debug)]
+pub enum GridError {
+    /// The source image couldn't be opened.
+    Image(image::ImageError),
+    /// The requested grid extends past the image bounds and the policy was
+    /// [`OutOfBoundsPolicy::Error`].
+    OutOfBounds {
+        image_width: u32,
+        image_height: u32,
+        grid_width: u32,
+        grid_height: u32,
+    },
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridError::Image(err) => write!(f, "image error: {err}"),
+            GridError::OutOfBounds {
+                image_width,
+                image_height,
+                grid_width,
+                grid_height,
+            } => write!(
+                f,
+                "grid of {grid_width}x{grid_height} does not fit inside image of {image_width}x{image_height}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for GridError {}
+
+impl From<image::ImageError> for GridError {
+    fn from(err: image::ImageError) -> Self {
+        GridError::Image(err)
+    }
+}
+
+/// Slices a rectangular inset grid out of a source image, rather than tiling
+/// the whole image.
+///
+/// Starting at `(spec.start_x, spec.start_y)`, extracts exactly
+/// `spec.rows * spec.cols` cells of `spec.cell_width * spec.cell_height`, in
+/// row-major order. This supports sprite-sheet workflows where the
+/// meaningful content is an inset grid with margins/padding around it. If the
+/// requested grid extends past the image bounds, `policy` decides whether
+/// that's an error ([`OutOfBoundsPolicy::Error`]) or every cell that extends
+/// past the image edge gets clipped to what's available
+/// ([`OutOfBoundsPolicy::Clip`]); a cell lying wholly outside the image comes
+/// back with zero width or height, so the result still holds
+/// `spec.rows * spec.cols` cells.
+///
+/// # Arguments
+///
+/// * `image_path` - Path to the input image file
+/// * `spec` - The grid to extract
+/// * `policy` - How to handle a grid that extends past the image bounds
+///
+/// # Returns
+///
+/// * `Result<Vec<ImageBuffer<Rgba<u8>, Vec<u8>>>, GridError>` - the
+///   `spec.rows * spec.cols` cells in row-major order, or an error
+///
+/// # Example
+///
+/// ```no_run
+/// use image_splitting::{split_grid, GridSpec, OutOfBoundsPolicy};
+///
+/// let sub_images = split_grid(
+///     "path/to/spritesheet.png",
+///     GridSpec { start_x: 8, start_y: 8, cell_width: 32, cell_height: 32, rows: 4, cols: 4 },
+///     OutOfBoundsPolicy::Error,
+/// )?;
+/// assert_eq!(sub_images.len(), 16);
+/// # Ok::<(), image_splitting::GridError>(())
+/// ```
+pub fn split_grid<P: AsRef<Path>>(
+    image_path: P,
+    spec: GridSpec,
+    policy: OutOfBoundsPolicy,
+) -> Result<Vec<ImageBuffer<Rgba<u8>, Vec<u8>>>, GridError> {
+    // Load the image
+    let img = image::open(image_path)?;
+
+    // Get image dimensions
+    let (width, height) = img.dimensions();
+
+    // Saturate rather than overflow so an oversized spec (e.g. a huge
+    // cell_width) is reported as out of bounds instead of panicking.
+    let grid_width = spec.cell_width.saturating_mul(spec.cols);
+    let grid_height = spec.cell_height.saturating_mul(spec.rows);
+    let end_x = spec.start_x.saturating_add(grid_width);
+    let end_y = spec.start_y.saturating_add(grid_height);
+
+    if end_x > width || end_y > height {
+        match policy {
+            OutOfBoundsPolicy::Error => {
+                return Err(GridError::OutOfBounds {
+                    image_width: width,
+                    image_height: height,
+                    grid_width,
+                    grid_height,
+                });
+            }
+            OutOfBoundsPolicy::Clip => {}
+        }
+    }
+
+    let mut sub_images = Vec::with_capacity((spec.rows * spec.cols) as usize);
+
+    for row in 0..spec.rows {
+        for col in 0..spec.cols {
+            let x = spec.start_x.saturating_add(col.saturating_mul(spec.cell_width));
+            let y = spec.start_y.saturating_add(row.saturating_mul(spec.cell_height));
+
+            let cell_width = spec.cell_width.min(width.saturating_sub(x));
+            let cell_height = spec.cell_height.min(height.saturating_sub(y));
+
+            let sub_img = img.crop_imm(x, y, cell_width, cell_height);
+            sub_images.push(sub_img.to_rgba8());
+        }
+    }
+
+    Ok(sub_images)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -137,4 +766,179 @@ mod tests {
         let sub_images = result.unwrap();
         assert!(sub_images.len() > 0);
     }
+
+    #[test]
+    fn test_split_directory() {
+        let dir = std::env::temp_dir().join("image_splitting_test_split_directory");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        fs::copy("tests/test_image.png", dir.join("valid.png")).unwrap();
+        fs::write(dir.join("corrupt.png"), b"not a real image").unwrap();
+        fs::write(dir.join("notes.txt"), b"not an image at all").unwrap();
+
+        let results = split_directory(&dir, 100, 100);
+
+        // The unsupported extension is skipped entirely, the valid image is
+        // split, and the corrupt one is reported as an error rather than
+        // aborting the run.
+        assert_eq!(results.len(), 2);
+        assert!(!results.contains_key(&dir.join("notes.txt")));
+
+        let valid_result = results.get(&dir.join("valid.png")).unwrap();
+        assert!(valid_result.is_ok());
+
+        let corrupt_result = results.get(&dir.join("corrupt.png")).unwrap();
+        assert!(corrupt_result.is_err());
+    }
+
+    #[test]
+    fn test_split_image_weighted() {
+        let result = split_image_weighted("tests/test_image.png", &[2, 3, 1, 5], &[1, 1]);
+        assert!(result.is_ok());
+        let sub_images = result.unwrap();
+        assert_eq!(sub_images.len(), 8);
+    }
+
+    #[test]
+    fn test_weighted_boundaries_covers_dimension_exactly() {
+        let boundaries = weighted_boundaries(&[2, 3, 1, 5], 110).unwrap();
+        assert_eq!(boundaries, vec![0, 20, 50, 60, 110]);
+    }
+
+    #[test]
+    fn test_weighted_boundaries_rejects_all_zero_weights() {
+        assert!(weighted_boundaries(&[0, 0, 0], 110).is_none());
+    }
+
+    #[test]
+    fn test_split_image_weighted_rejects_zero_weights() {
+        let result = split_image_weighted("tests/test_image.png", &[0, 0], &[1, 1]);
+        assert!(matches!(result, Err(SplitWeightedError::ZeroWeight)));
+    }
+
+    #[test]
+    fn test_split_and_save() {
+        let dir = std::env::temp_dir().join("image_splitting_test_split_and_save");
+        let result = split_and_save(
+            "tests/test_image.png",
+            100,
+            100,
+            &dir,
+            "tile",
+            image::ImageFormat::Png,
+        );
+        assert!(result.is_ok());
+        let paths = result.unwrap();
+        assert!(paths.iter().all(|path| path.exists()));
+        assert_eq!(
+            paths[0].file_name().unwrap().to_str().unwrap(),
+            "tile_0_0.png"
+        );
+    }
+
+    #[test]
+    fn test_split_views() {
+        let source = image::open("tests/test_image.png").unwrap().to_rgba8();
+        let views: Vec<_> = split_views(&source, 100, 100).collect();
+        assert!(!views.is_empty());
+
+        let owned = views[0].to_owned();
+        assert_eq!(owned.width(), views[0].width);
+        assert_eq!(owned.height(), views[0].height);
+    }
+
+    #[test]
+    fn test_split_view_rows_match_to_owned() {
+        let source = image::open("tests/test_image.png").unwrap().to_rgba8();
+        let view = split_views(&source, 100, 100).next().unwrap();
+
+        let owned = view.to_owned();
+        let rows: Vec<&[u8]> = view.rows().collect();
+        let row_bytes = view.width as usize * 4;
+
+        assert_eq!(rows.len(), view.height as usize);
+        for (row, samples) in rows.iter().enumerate() {
+            assert_eq!(samples.len(), row_bytes);
+            assert_eq!(*samples, &owned.as_raw()[row * row_bytes..(row + 1) * row_bytes]);
+        }
+    }
+
+    #[test]
+    fn test_split_grid() {
+        let spec = GridSpec {
+            start_x: 0,
+            start_y: 0,
+            cell_width: 50,
+            cell_height: 50,
+            rows: 2,
+            cols: 2,
+        };
+        let result = split_grid("tests/test_image.png", spec, OutOfBoundsPolicy::Error);
+        assert!(result.is_ok());
+        let sub_images = result.unwrap();
+        assert_eq!(sub_images.len(), 4);
+    }
+
+    #[test]
+    fn test_split_grid_out_of_bounds_errors() {
+        let spec = GridSpec {
+            start_x: 0,
+            start_y: 0,
+            cell_width: 10_000,
+            cell_height: 10_000,
+            rows: 2,
+            cols: 2,
+        };
+        let result = split_grid("tests/test_image.png", spec, OutOfBoundsPolicy::Error);
+        assert!(matches!(result, Err(GridError::OutOfBounds { .. })));
+    }
+
+    #[test]
+    fn test_split_grid_clip_policy_clips_to_available_space() {
+        let spec = GridSpec {
+            start_x: 0,
+            start_y: 0,
+            cell_width: 10_000,
+            cell_height: 10_000,
+            rows: 1,
+            cols: 1,
+        };
+        let result = split_grid("tests/test_image.png", spec, OutOfBoundsPolicy::Clip);
+        assert!(result.is_ok());
+        let sub_images = result.unwrap();
+        assert_eq!(sub_images.len(), 1);
+        assert!(sub_images[0].width() <= spec.cell_width);
+        assert!(sub_images[0].height() <= spec.cell_height);
+    }
+
+    #[test]
+    fn test_split_grid_overflowing_spec_errors_instead_of_panicking() {
+        let spec = GridSpec {
+            start_x: 0,
+            start_y: 0,
+            cell_width: u32::MAX,
+            cell_height: u32::MAX,
+            rows: 2,
+            cols: 2,
+        };
+        let result = split_grid("tests/test_image.png", spec, OutOfBoundsPolicy::Error);
+        assert!(matches!(result, Err(GridError::OutOfBounds { .. })));
+    }
+
+    #[test]
+    fn test_split_grid_overflowing_spec_clips_instead_of_panicking() {
+        let spec = GridSpec {
+            start_x: 0,
+            start_y: 0,
+            cell_width: 3_000_000_000,
+            cell_height: 3_000_000_000,
+            rows: 3,
+            cols: 3,
+        };
+        let result = split_grid("tests/test_image.png", spec, OutOfBoundsPolicy::Clip);
+        assert!(result.is_ok());
+        let sub_images = result.unwrap();
+        assert_eq!(sub_images.len(), 9);
+    }
 }